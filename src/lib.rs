@@ -0,0 +1 @@
+pub mod prisma_value;
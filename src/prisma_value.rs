@@ -3,7 +3,8 @@ use std::sync::Arc;
 use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
 use chrono::{DateTime, FixedOffset};
 use indexmap::IndexMap;
-use serde::{Serialize, Serializer};
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
 /// A Rust-friendly version of Prisma's own PrismaValue.
@@ -19,20 +20,35 @@ pub enum PrismaValue {
     String(String),
     Boolean(bool),
     Enum(String),
-    Int(i32),
+    Int(i64),
     Uuid(Uuid),
     List(Vec<PrismaValue>),
     Json(serde_json::Value),
+    RawJson(Box<serde_json::value::RawValue>),
     Xml(String),
+    #[serde(serialize_with = "serialize_object")]
     Object(Vec<(String, PrismaValue)>),
     #[serde(serialize_with = "serialize_null")]
     Null,
     DateTime(DateTime<FixedOffset>),
     Float(f64),
+    /// An arbitrary-precision decimal. Note that it serializes as a bare JSON number, so a serde
+    /// round-trip recovers a fractional value as [`PrismaValue::Float`] and a whole number as
+    /// [`PrismaValue::Int`] (failing if it is above `i64::MAX` but fits in a `u64`) — the precision
+    /// only survives a one-way read out of the query engine, not a serialize/deserialize cycle
+    /// (see the `Deserialize` impl).
+    #[serde(serialize_with = "serialize_decimal")]
+    Decimal(BigDecimal),
     BigInt(i64),
+    #[serde(serialize_with = "serialize_bytes")]
     Bytes(Vec<u8>),
 }
 
+/// Sentinel key used to tag base64-encoded `Bytes` so they survive an untagged round-trip.
+/// A bare array would be indistinguishable from `List`, and a bare string from `String`, so
+/// bytes are wrapped in a single-entry object under this key instead.
+const BYTES_SENTINEL: &str = "$bytes";
+
 /// A Rust-friendly version of Prisma's own Item.
 /// Exists solely for nicer conversion of query results to our PrismaValue.
 #[derive(Clone, Serialize)]
@@ -44,21 +60,73 @@ pub enum Item {
     Json(serde_json::Value),
 }
 
-impl From<query_core::Item> for Item {
-    fn from(item: query_core::Item) -> Self {
-        match item {
-            query_core::Item::Map(map) => {
-                Item::Map(map.into_iter().map(|(k, v)| (k, v.into())).collect())
+/// An error produced while converting between `prisma_models`' values and our own.
+///
+/// The query engine can hand back numbers and JSON that don't fit cleanly into the
+/// corresponding Rust type. Rather than panicking the whole client on the offending
+/// row, these conversions surface the problem as a recoverable `Result`.
+#[derive(Debug, Clone)]
+pub enum ValueConversionError {
+    /// The engine returned a JSON column whose contents are not valid JSON.
+    InvalidJson(String),
+    /// A float could not be represented as a `BigDecimal` (NaN or infinity).
+    NonFiniteFloat(f64),
+    /// An integer does not fit in the target integer type (raised when deserializing a JSON
+    /// integer above `i64::MAX`).
+    IntOverflow(i128),
+}
+
+impl std::fmt::Display for ValueConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueConversionError::InvalidJson(value) => {
+                write!(f, "invalid JSON returned by the query engine: {value}")
+            }
+            ValueConversionError::NonFiniteFloat(value) => {
+                write!(f, "cannot convert non-finite float {value}")
             }
-            query_core::Item::List(list) => {
-                Item::List(list.into_iter().map(|v| v.into()).collect())
+            ValueConversionError::IntOverflow(value) => {
+                write!(f, "integer {value} does not fit in the target type")
             }
-            query_core::Item::Value(scalar) => Item::Value(scalar.into()),
+        }
+    }
+}
+
+impl std::error::Error for ValueConversionError {}
+
+impl PrismaValue {
+    /// Return the integer value narrowed to `i32`, or `None` if it is out of range or the
+    /// value is not an `Int`. Use this where an `i32`-typed API must be preserved rather than
+    /// silently truncating a 64-bit integer.
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            PrismaValue::Int(value) => i32::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<query_core::Item> for Item {
+    type Error = ValueConversionError;
+
+    fn try_from(item: query_core::Item) -> Result<Self, Self::Error> {
+        Ok(match item {
+            query_core::Item::Map(map) => Item::Map(
+                map.into_iter()
+                    .map(|(k, v)| Ok((k, v.try_into()?)))
+                    .collect::<Result<_, Self::Error>>()?,
+            ),
+            query_core::Item::List(list) => Item::List(
+                list.into_iter()
+                    .map(Item::try_from)
+                    .collect::<Result<_, Self::Error>>()?,
+            ),
+            query_core::Item::Value(scalar) => Item::Value(scalar.try_into()?),
             query_core::Item::Json(json) => Item::Json(json),
             query_core::Item::Ref(arc) => Arc::try_unwrap(arc)
                 .unwrap_or_else(|arc| (*arc).to_owned())
-                .into(),
-        }
+                .try_into()?,
+        })
     }
 }
 
@@ -69,58 +137,518 @@ where
     Option::<()>::None.serialize(serializer)
 }
 
-impl From<prisma_models::PrismaValue> for PrismaValue {
-    fn from(value: prisma_models::PrismaValue) -> Self {
-        match value {
+/// Serialize `Bytes` as a single-entry object `{"$bytes": "<base64>"}` so the value is
+/// distinguishable from a `List`/`String` and can be recovered by the custom `Deserialize`.
+fn serialize_bytes<S>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(BYTES_SENTINEL, &base64::encode(value))?;
+    map.end()
+}
+
+/// Serialize `Object` as a JSON map (rather than an array of `[key, value]` pairs), preserving
+/// insertion order, so it round-trips symmetrically through the map branch of `Deserialize`.
+fn serialize_object<S>(value: &[(String, PrismaValue)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(value.len()))?;
+    for (key, value) in value {
+        map.serialize_entry(key, value)?;
+    }
+    map.end()
+}
+
+/// Serialize a `BigDecimal` as a JSON number built from its decimal string, so that
+/// arbitrary-precision values survive serialization without being rounded through an `f64`.
+fn serialize_decimal<S>(value: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let raw = serde_json::value::RawValue::from_string(value.normalized().to_string())
+        .map_err(serde::ser::Error::custom)?;
+    raw.serialize(serializer)
+}
+
+impl TryFrom<prisma_models::PrismaValue> for PrismaValue {
+    type Error = ValueConversionError;
+
+    fn try_from(value: prisma_models::PrismaValue) -> Result<Self, Self::Error> {
+        Ok(match value {
             prisma_models::PrismaValue::String(value) => PrismaValue::String(value),
             prisma_models::PrismaValue::Boolean(value) => PrismaValue::Boolean(value),
             prisma_models::PrismaValue::Enum(value) => PrismaValue::Enum(value),
-            prisma_models::PrismaValue::Int(value) => PrismaValue::Int(value as i32),
+            prisma_models::PrismaValue::Int(value) => PrismaValue::Int(value),
             prisma_models::PrismaValue::Uuid(value) => PrismaValue::Uuid(value.into()),
-            prisma_models::PrismaValue::List(value) => {
-                PrismaValue::List(value.into_iter().map(Into::into).collect())
-            }
-            prisma_models::PrismaValue::Json(value) => {
-                PrismaValue::Json(serde_json::from_str(&value).unwrap())
-            }
+            prisma_models::PrismaValue::List(value) => PrismaValue::List(
+                value
+                    .into_iter()
+                    .map(PrismaValue::try_from)
+                    .collect::<Result<_, Self::Error>>()?,
+            ),
+            prisma_models::PrismaValue::Json(value) => PrismaValue::RawJson(
+                serde_json::value::RawValue::from_string(value.clone())
+                    .map_err(|_| ValueConversionError::InvalidJson(value))?,
+            ),
             prisma_models::PrismaValue::Xml(value) => PrismaValue::Xml(value),
-            prisma_models::PrismaValue::Object(value) => {
-                PrismaValue::Object(value.into_iter().map(|(k, v)| (k, v.into())).collect())
-            }
+            prisma_models::PrismaValue::Object(value) => PrismaValue::Object(
+                value
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, v.try_into()?)))
+                    .collect::<Result<_, Self::Error>>()?,
+            ),
             prisma_models::PrismaValue::Null => PrismaValue::Null,
             prisma_models::PrismaValue::DateTime(value) => PrismaValue::DateTime(value),
-            prisma_models::PrismaValue::Float(value) => PrismaValue::Float(value.to_f64().unwrap()),
+            prisma_models::PrismaValue::Float(value) => match value.to_f64() {
+                // Keep the common case as a plain `f64`, but only when it round-trips exactly;
+                // anything that would lose precision or range is preserved as a decimal.
+                Some(float) if BigDecimal::from_f64(float).as_ref() == Some(&value) => {
+                    PrismaValue::Float(float)
+                }
+                _ => PrismaValue::Decimal(value),
+            },
             prisma_models::PrismaValue::BigInt(value) => PrismaValue::BigInt(value),
             prisma_models::PrismaValue::Bytes(value) => PrismaValue::Bytes(value),
-        }
+        })
     }
 }
 
-impl Into<prisma_models::PrismaValue> for PrismaValue {
-    fn into(self) -> prisma_models::PrismaValue {
-        match self {
+impl TryFrom<PrismaValue> for prisma_models::PrismaValue {
+    type Error = ValueConversionError;
+
+    fn try_from(value: PrismaValue) -> Result<Self, Self::Error> {
+        Ok(match value {
             PrismaValue::String(value) => prisma_models::PrismaValue::String(value),
             PrismaValue::Boolean(value) => prisma_models::PrismaValue::Boolean(value),
             PrismaValue::Enum(value) => prisma_models::PrismaValue::Enum(value),
-            PrismaValue::Int(value) => prisma_models::PrismaValue::Int(value as i64),
+            PrismaValue::Int(value) => prisma_models::PrismaValue::Int(value),
             PrismaValue::Uuid(value) => prisma_models::PrismaValue::Uuid(value),
-            PrismaValue::List(value) => {
-                prisma_models::PrismaValue::List(value.into_iter().map(Into::into).collect())
-            }
-            PrismaValue::Json(value) => {
-                prisma_models::PrismaValue::Json(serde_json::to_string(&value).unwrap())
+            PrismaValue::List(value) => prisma_models::PrismaValue::List(
+                value
+                    .into_iter()
+                    .map(prisma_models::PrismaValue::try_from)
+                    .collect::<Result<_, Self::Error>>()?,
+            ),
+            PrismaValue::Json(value) => prisma_models::PrismaValue::Json(
+                serde_json::to_string(&value)
+                    .map_err(|e| ValueConversionError::InvalidJson(e.to_string()))?,
+            ),
+            PrismaValue::RawJson(value) => {
+                prisma_models::PrismaValue::Json(value.get().to_owned())
             }
             PrismaValue::Xml(value) => prisma_models::PrismaValue::Xml(value),
             PrismaValue::Object(value) => prisma_models::PrismaValue::Object(
-                value.into_iter().map(|(k, v)| (k, v.into())).collect(),
+                value
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, v.try_into()?)))
+                    .collect::<Result<_, Self::Error>>()?,
             ),
             PrismaValue::Null => prisma_models::PrismaValue::Null,
             PrismaValue::DateTime(value) => prisma_models::PrismaValue::DateTime(value),
-            PrismaValue::Float(value) => {
-                prisma_models::PrismaValue::Float(BigDecimal::from_f64(value).unwrap())
-            }
+            PrismaValue::Float(value) => prisma_models::PrismaValue::Float(
+                BigDecimal::from_f64(value)
+                    .ok_or(ValueConversionError::NonFiniteFloat(value))?,
+            ),
+            PrismaValue::Decimal(value) => prisma_models::PrismaValue::Float(value),
             PrismaValue::BigInt(value) => prisma_models::PrismaValue::BigInt(value),
             PrismaValue::Bytes(value) => prisma_models::PrismaValue::Bytes(value),
+        })
+    }
+}
+
+/// Build a `PrismaValue` from a signed integer. `Int` and `BigInt` both hold an `i64` and
+/// serialize identically, so any whole number recovers as the `Int` variant.
+fn prisma_value_from_i64(value: i64) -> PrismaValue {
+    PrismaValue::Int(value)
+}
+
+/// Build a `PrismaValue` from a string, recovering the richer scalar types that serialize
+/// as JSON strings: RFC3339 timestamps become `DateTime`, UUIDs become `Uuid`, and everything
+/// else stays a plain `String`. `Enum`/`Xml` are indistinguishable from `String` once
+/// serialized untagged, so they round-trip as `String`.
+fn prisma_value_from_string(value: String) -> PrismaValue {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(&value) {
+        return PrismaValue::DateTime(datetime);
+    }
+
+    if let Ok(uuid) = Uuid::parse_str(&value) {
+        return PrismaValue::Uuid(uuid);
+    }
+
+    PrismaValue::String(value)
+}
+
+struct PrismaValueVisitor;
+
+impl<'de> Visitor<'de> for PrismaValueVisitor {
+    type Value = PrismaValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(PrismaValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(PrismaValue::Null)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(PrismaValue::Boolean(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(prisma_value_from_i64(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match i64::try_from(value) {
+            Ok(value) => Ok(prisma_value_from_i64(value)),
+            Err(_) => Err(de::Error::custom(ValueConversionError::IntOverflow(
+                value as i128,
+            ))),
+        }
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(PrismaValue::Float(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(prisma_value_from_string(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(prisma_value_from_string(value))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            list.push(value);
         }
+        Ok(PrismaValue::List(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((key, value)) = map.next_entry()? {
+            object.push((key, value));
+        }
+
+        if let Some(bytes) = decode_bytes_sentinel(&object) {
+            return Ok(PrismaValue::Bytes(bytes));
+        }
+
+        Ok(PrismaValue::Object(object))
+    }
+}
+
+/// Recover the raw bytes from a `{"$bytes": "<base64>"}` object produced by [`serialize_bytes`],
+/// returning `None` for any other object so it stays a regular map.
+fn decode_bytes_sentinel(object: &[(String, PrismaValue)]) -> Option<Vec<u8>> {
+    if let [(key, PrismaValue::String(encoded))] = object {
+        if key == BYTES_SENTINEL {
+            return base64::decode(encoded).ok();
+        }
+    }
+    None
+}
+
+/// Recovers a `PrismaValue` from the untagged serialized form. Some variants that serialize to an
+/// indistinguishable JSON shape collapse on the way back: `Enum`/`Xml` become `String`, and
+/// `Decimal` becomes `Float`, or `Int` for a whole number (a bare JSON number carries no precision
+/// hint), so money/high-precision
+/// columns only keep their full precision on the one-way read out of the query engine.
+impl<'de> Deserialize<'de> for PrismaValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(PrismaValueVisitor)
+    }
+}
+
+struct ItemVisitor;
+
+impl<'de> Visitor<'de> for ItemVisitor {
+    type Value = Item;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            list.push(value);
+        }
+        Ok(Item::List(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object: IndexMap<String, Item> = IndexMap::new();
+        while let Some((key, value)) = map.next_entry()? {
+            object.insert(key, value);
+        }
+
+        if object.len() == 1 {
+            if let Some(Item::Value(PrismaValue::String(encoded))) = object.get(BYTES_SENTINEL) {
+                if let Ok(bytes) = base64::decode(encoded) {
+                    return Ok(Item::Value(PrismaValue::Bytes(bytes)));
+                }
+            }
+        }
+
+        Ok(Item::Map(object))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Item::Value(PrismaValue::Null))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Item::Value(PrismaValue::Null))
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Item::Value(PrismaValue::Boolean(value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Item::Value(prisma_value_from_i64(value)))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        PrismaValueVisitor.visit_u64(value).map(Item::Value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Item::Value(PrismaValue::Float(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Item::Value(prisma_value_from_string(value.to_owned())))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Item::Value(prisma_value_from_string(value)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ItemVisitor)
+    }
+}
+
+/// A columnar encoding of a homogeneous list of [`Item::Map`] records.
+///
+/// The default map-shaped serialization repeats every field name in every row, which dominates
+/// the payload on wide result sets. `TabularResult` hoists the shared key set into `header` once
+/// and stores each record positionally in `rows`, null-filling any field a given record is
+/// missing. Encode with [`Item::to_tabular`] and decode with [`TabularResult::into_items`]; the
+/// map layout stays the default, so this is strictly an opt-in for callers streaming large lists.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TabularResult {
+    /// The union of field names across all records, in first-seen order.
+    pub header: Vec<String>,
+    /// One positional row per record, each aligned to `header`.
+    pub rows: Vec<Vec<Item>>,
+}
+
+impl Item {
+    /// Encode a slice of `Item::Map` records into the compact [`TabularResult`] layout.
+    ///
+    /// Keys are collected in first-seen order across every record, and each record is emitted as
+    /// a positional row, null-filling absent fields. Records that are not `Item::Map` contribute
+    /// no keys and encode as an all-null row.
+    pub fn to_tabular(items: &[Item]) -> TabularResult {
+        let mut header: Vec<String> = Vec::new();
+        for item in items {
+            if let Item::Map(map) = item {
+                for key in map.keys() {
+                    if !header.iter().any(|existing| existing == key) {
+                        header.push(key.clone());
+                    }
+                }
+            }
+        }
+
+        let rows = items
+            .iter()
+            .map(|item| {
+                header
+                    .iter()
+                    .map(|key| match item {
+                        Item::Map(map) => map
+                            .get(key)
+                            .cloned()
+                            .unwrap_or(Item::Value(PrismaValue::Null)),
+                        _ => Item::Value(PrismaValue::Null),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        TabularResult { header, rows }
+    }
+}
+
+impl TabularResult {
+    /// Decode the columnar layout back into map-shaped [`Item`]s, pairing each positional row
+    /// with the shared `header` and preserving key order.
+    pub fn into_items(self) -> Vec<Item> {
+        let TabularResult { header, rows } = self;
+
+        rows.into_iter()
+            .map(|row| {
+                Item::Map(
+                    header
+                        .iter()
+                        .cloned()
+                        .zip(row)
+                        .collect::<IndexMap<String, Item>>(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serialize a value to JSON and read it back as the same type.
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let value = PrismaValue::Bytes(vec![0, 1, 2, 254, 255]);
+
+        // The on-wire form is distinguishable from a list of integers.
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(json.starts_with("{\"$bytes\":"));
+
+        match round_trip(&value) {
+            PrismaValue::Bytes(bytes) => assert_eq!(bytes, vec![0, 1, 2, 254, 255]),
+            other => panic!("expected Bytes, got {:?}", serde_json::to_string(&other)),
+        }
+    }
+
+    #[test]
+    fn item_bytes_round_trip() {
+        let item = Item::Value(PrismaValue::Bytes(vec![42, 7]));
+
+        match round_trip(&item) {
+            Item::Value(PrismaValue::Bytes(bytes)) => assert_eq!(bytes, vec![42, 7]),
+            other => panic!("expected Bytes, got {:?}", serde_json::to_string(&other)),
+        }
+    }
+
+    #[test]
+    fn large_int_is_not_truncated() {
+        let value = PrismaValue::Int(10_000_000_000);
+
+        match round_trip(&value) {
+            PrismaValue::Int(int) => assert_eq!(int, 10_000_000_000),
+            other => panic!("expected Int, got {:?}", serde_json::to_string(&other)),
+        }
+    }
+
+    #[test]
+    fn scalar_shapes_round_trip() {
+        let datetime =
+            DateTime::parse_from_rfc3339("2023-01-02T03:04:05+00:00").unwrap();
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for value in [
+            PrismaValue::Null,
+            PrismaValue::Boolean(true),
+            PrismaValue::String("hello".to_owned()),
+            PrismaValue::Float(1.5),
+            PrismaValue::DateTime(datetime),
+            PrismaValue::Uuid(uuid),
+            PrismaValue::List(vec![PrismaValue::Int(1), PrismaValue::Boolean(false)]),
+        ] {
+            let json = serde_json::to_string(&value).unwrap();
+            let reserialized = serde_json::to_string(&round_trip(&value)).unwrap();
+            assert_eq!(json, reserialized);
+        }
+    }
+
+    #[test]
+    fn object_round_trips_as_object() {
+        let object = PrismaValue::Object(vec![
+            ("a".to_owned(), PrismaValue::Int(1)),
+            ("b".to_owned(), PrismaValue::String("x".to_owned())),
+        ]);
+
+        let recovered = round_trip(&object);
+        assert!(
+            matches!(recovered, PrismaValue::Object(_)),
+            "expected Object, got {:?}",
+            serde_json::to_string(&recovered)
+        );
+
+        // Key order is preserved through the round-trip.
+        let json = serde_json::to_string(&object).unwrap();
+        assert_eq!(json, serde_json::to_string(&recovered).unwrap());
+    }
+
+    #[test]
+    fn tabular_round_trips_map_items() {
+        let mut first = IndexMap::new();
+        first.insert("id".to_owned(), Item::Value(PrismaValue::Int(1)));
+        first.insert("name".to_owned(), Item::Value(PrismaValue::String("a".to_owned())));
+
+        let mut second = IndexMap::new();
+        second.insert("id".to_owned(), Item::Value(PrismaValue::Int(2)));
+
+        let items = vec![Item::Map(first), Item::Map(second)];
+        let tabular = Item::to_tabular(&items);
+
+        assert_eq!(tabular.header, vec!["id".to_owned(), "name".to_owned()]);
+        assert_eq!(tabular.rows.len(), 2);
+
+        let decoded = tabular.into_items();
+        let before = serde_json::to_string(&items).unwrap();
+        let after = serde_json::to_string(&decoded).unwrap();
+        assert_eq!(before, after);
     }
 }